@@ -2,7 +2,7 @@
 
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, panic_with_error,
-    Address, Env, Vec,
+    token, Address, Env, Symbol, Vec,
 };
 
 const MAX_MEMBERS: u32 = 50;
@@ -12,6 +12,8 @@ const MAX_MEMBERS: u32 = 50;
 pub enum DataKey {
     Circle(u32),
     CircleCount,
+    Vesting(u32, u32),
+    Candidate(u32, Address),
 }
 
 #[derive(Clone)]
@@ -19,6 +21,7 @@ pub enum DataKey {
 pub struct Circle {
     admin: Address,
     contribution: i128,
+    token: Address,
     members: Vec<Address>,
     is_random_queue: bool,
     payout_queue: Vec<Address>,
@@ -35,6 +38,45 @@ pub struct Circle {
 
     // accounting
     contributions_paid: Vec<i128>,
+
+    // strikes / slashing
+    strikes: Vec<u32>,
+    max_strikes: u32,
+    slashed_pot: i128,
+    slashed_pot_claimed: Vec<bool>,
+
+    // rotation cadence
+    rotation_period: u64,
+    round_started_at: u64,
+    round_deadline: u64,
+
+    // vesting
+    vesting_duration: u64,
+
+    // candidate bidding / vouching
+    wrong_side_deduction: i128,
+
+    // per-round funding (actually escrowed, not assumed)
+    round_contributions: Vec<bool>,
+    round_pool: i128,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct VestingSchedule {
+    beneficiary: Address,
+    total: i128,
+    start_ts: u64,
+    duration: u64,
+    claimed: i128,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Candidacy {
+    vouched_by: Option<Address>,
+    deposit: i128,
+    approvals: Vec<Address>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -48,6 +90,23 @@ pub enum Error {
     NotMember = 1006,
     AlreadyDissolved = 1007,
     NotDissolved = 1008,
+    MaxStrikesReached = 1009,
+    RoundNotDue = 1010,
+    NoVestingSchedule = 1011,
+    CandidateNotFound = 1012,
+    AlreadyCandidate = 1013,
+    OutOfOrder = 1014,
+    RoundNotFunded = 1015,
+    AlreadyContributed = 1016,
+    InsufficientDeposit = 1017,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct RoundStatus {
+    pub recipient: Address,
+    pub amount_collected: i128,
+    pub seconds_remaining: u64,
 }
 
 #[contract]
@@ -64,6 +123,48 @@ fn write_circle(env: &Env, id: u32, circle: &Circle) {
     env.storage().instance().set(&DataKey::Circle(id), circle);
 }
 
+fn read_vesting(env: &Env, circle_id: u32, index: u32) -> VestingSchedule {
+    match env.storage().instance().get(&DataKey::Vesting(circle_id, index)) {
+        Some(v) => v,
+        None => panic_with_error!(env, Error::NoVestingSchedule),
+    }
+}
+
+fn write_vesting(env: &Env, circle_id: u32, index: u32, vesting: &VestingSchedule) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Vesting(circle_id, index), vesting);
+}
+
+fn read_candidate(env: &Env, circle_id: u32, candidate: &Address) -> Candidacy {
+    match env
+        .storage()
+        .instance()
+        .get(&DataKey::Candidate(circle_id, candidate.clone()))
+    {
+        Some(c) => c,
+        None => panic_with_error!(env, Error::CandidateNotFound),
+    }
+}
+
+fn write_candidate(env: &Env, circle_id: u32, candidate: &Address, candidacy: &Candidacy) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Candidate(circle_id, candidate.clone()), candidacy);
+}
+
+fn has_candidate(env: &Env, circle_id: u32, candidate: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::Candidate(circle_id, candidate.clone()))
+}
+
+fn remove_candidate(env: &Env, circle_id: u32, candidate: &Address) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::Candidate(circle_id, candidate.clone()));
+}
+
 fn next_circle_id(env: &Env) -> u32 {
     let key = DataKey::CircleCount;
     let current: u32 = env.storage().instance().get(&key).unwrap_or(0);
@@ -79,13 +180,23 @@ impl SoroSusu {
     // CREATE
     // ============================================================
 
-    pub fn create_circle(env: Env, contribution: i128, is_random_queue: bool) -> u32 {
+    pub fn create_circle(
+        env: Env,
+        contribution: i128,
+        token: Address,
+        is_random_queue: bool,
+        max_strikes: u32,
+        rotation_period: u64,
+        vesting_duration: u64,
+        wrong_side_deduction: i128,
+    ) -> u32 {
         let admin = env.invoker();
         let id = next_circle_id(&env);
 
         let circle = Circle {
             admin,
             contribution,
+            token,
             members: Vec::new(&env),
             is_random_queue,
             payout_queue: Vec::new(&env),
@@ -96,9 +207,26 @@ impl SoroSusu {
             is_dissolved: false,
             dissolution_votes: Vec::new(&env),
             contributions_paid: Vec::new(&env),
+            strikes: Vec::new(&env),
+            max_strikes,
+            slashed_pot: 0,
+            slashed_pot_claimed: Vec::new(&env),
+            rotation_period,
+            round_started_at: 0,
+            round_deadline: 0,
+            vesting_duration,
+            wrong_side_deduction,
+            round_contributions: Vec::new(&env),
+            round_pool: 0,
         };
 
         write_circle(&env, id, &circle);
+
+        env.events().publish(
+            (Symbol::new(&env, "CircleCreated"), id),
+            (circle.admin.clone(), circle.contribution),
+        );
+
         id
     }
 
@@ -122,13 +250,204 @@ impl SoroSusu {
             panic_with_error!(&env, Error::MaxMembersReached);
         }
 
-        circle.members.push_back(invoker);
+        invoker.require_auth();
+
+        let token_client = token::Client::new(&env, &circle.token);
+        token_client.transfer(&invoker, &env.current_contract_address(), &circle.contribution);
+
+        circle.members.push_back(invoker.clone());
         circle.has_received_payout.push_back(false);
         circle.contributions_paid.push_back(circle.contribution);
+        circle.strikes.push_back(0);
+        circle.slashed_pot_claimed.push_back(false);
+
+        // the join-time escrow funds the first round, so this member is
+        // already paid up until the first payout resets the cadence.
+        circle.round_contributions.push_back(true);
+        circle.round_pool += circle.contribution;
+
+        write_circle(&env, circle_id, &circle);
+
+        env.events()
+            .publish((Symbol::new(&env, "MemberJoined"), circle_id), invoker);
+    }
+
+    // ============================================================
+    // ROUND FUNDING
+    // ============================================================
+
+    pub fn contribute(env: Env, circle_id: u32) {
+        let invoker = env.invoker();
+        let mut circle = read_circle(&env, circle_id);
+
+        if circle.is_dissolved {
+            panic_with_error!(&env, Error::AlreadyDissolved);
+        }
+
+        if !circle.payout_queue.contains(&invoker) {
+            panic_with_error!(&env, Error::NotMember);
+        }
+
+        let mut index = None;
+        for (i, member) in circle.members.iter().enumerate() {
+            if member == invoker {
+                index = Some(i);
+                break;
+            }
+        }
+
+        let i = index.unwrap_or_else(|| panic_with_error!(&env, Error::NotMember));
+
+        if circle.round_contributions.get(i).unwrap() {
+            panic_with_error!(&env, Error::AlreadyContributed);
+        }
+
+        invoker.require_auth();
+
+        let token_client = token::Client::new(&env, &circle.token);
+        token_client.transfer(&invoker, &env.current_contract_address(), &circle.contribution);
+
+        circle.round_contributions.set(i, true);
+        circle
+            .contributions_paid
+            .set(i, circle.contributions_paid.get(i).unwrap() + circle.contribution);
+        circle.round_pool += circle.contribution;
 
         write_circle(&env, circle_id, &circle);
     }
 
+    // ============================================================
+    // CANDIDATE BIDDING / VOUCHING
+    // ============================================================
+
+    pub fn bid_to_join(env: Env, circle_id: u32, deposit: i128) {
+        let invoker = env.invoker();
+        let circle = read_circle(&env, circle_id);
+
+        if circle.is_dissolved {
+            panic_with_error!(&env, Error::AlreadyDissolved);
+        }
+
+        if circle.members.contains(&invoker) {
+            panic_with_error!(&env, Error::AlreadyJoined);
+        }
+
+        if has_candidate(&env, circle_id, &invoker) {
+            panic_with_error!(&env, Error::AlreadyCandidate);
+        }
+
+        invoker.require_auth();
+
+        let token_client = token::Client::new(&env, &circle.token);
+        token_client.transfer(&invoker, &env.current_contract_address(), &deposit);
+
+        let candidacy = Candidacy {
+            vouched_by: None,
+            deposit,
+            approvals: Vec::new(&env),
+        };
+
+        write_candidate(&env, circle_id, &invoker, &candidacy);
+    }
+
+    pub fn vouch(env: Env, circle_id: u32, candidate: Address) {
+        let invoker = env.invoker();
+        let circle = read_circle(&env, circle_id);
+
+        if !circle.members.contains(&invoker) {
+            panic_with_error!(&env, Error::NotMember);
+        }
+
+        invoker.require_auth();
+
+        let mut candidacy = read_candidate(&env, circle_id, &candidate);
+
+        if candidacy.approvals.contains(&invoker) {
+            panic_with_error!(&env, Error::AlreadyVoted);
+        }
+
+        candidacy.approvals.push_back(invoker.clone());
+        if candidacy.vouched_by.is_none() {
+            candidacy.vouched_by = Some(invoker);
+        }
+
+        write_candidate(&env, circle_id, &candidate, &candidacy);
+    }
+
+    pub fn admit_candidate(env: Env, circle_id: u32, candidate: Address) {
+        let mut circle = read_circle(&env, circle_id);
+
+        if env.invoker() != circle.admin {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+
+        if circle.is_dissolved {
+            panic_with_error!(&env, Error::AlreadyDissolved);
+        }
+
+        let candidacy = read_candidate(&env, circle_id, &candidate);
+        remove_candidate(&env, circle_id, &candidate);
+
+        let token_client = token::Client::new(&env, &circle.token);
+
+        // require a real quorum of sponsoring members, not a single vouch,
+        // so admission reflects shared exposure to the candidate's risk.
+        let quorum_met = candidacy.approvals.len() * 2 > circle.members.len();
+
+        if quorum_met {
+            if circle.members.len() >= MAX_MEMBERS {
+                panic_with_error!(&env, Error::MaxMembersReached);
+            }
+
+            if candidacy.deposit < circle.contribution {
+                panic_with_error!(&env, Error::InsufficientDeposit);
+            }
+
+            circle.members.push_back(candidate.clone());
+            circle.has_received_payout.push_back(false);
+            circle.strikes.push_back(0);
+            circle.slashed_pot_claimed.push_back(false);
+
+            // the candidate's deposit funds their first round contribution
+            // instead of being refunded, so membership actually escrows
+            // the stake every other member is putting up.
+            circle.contributions_paid.push_back(circle.contribution);
+            circle.round_contributions.push_back(true);
+            circle.round_pool += circle.contribution;
+
+            // if the circle was finalized already, `finalize_circle` will
+            // never rebuild `payout_queue` from `members` again, so this
+            // candidate would otherwise never be scheduled for a payout
+            // (or be allowed to call `contribute`). Append them to the
+            // live rotation directly. Before finalization the queue is
+            // still empty and `finalize_circle` seeds it from `members`,
+            // which already includes this candidate.
+            if !circle.payout_queue.is_empty() {
+                circle.payout_queue.push_back(candidate.clone());
+            }
+
+            write_circle(&env, circle_id, &circle);
+
+            let refund = candidacy.deposit - circle.contribution;
+            if refund > 0 {
+                token_client.transfer(&env.current_contract_address(), &candidate, &refund);
+            }
+
+            env.events()
+                .publish((Symbol::new(&env, "MemberJoined"), circle_id), candidate);
+        } else {
+            let deduction = circle.wrong_side_deduction.min(candidacy.deposit);
+            circle.slashed_pot += deduction;
+
+            write_circle(&env, circle_id, &circle);
+
+            let remainder = candidacy.deposit - deduction;
+            if remainder > 0 {
+                token_client.transfer(&env.current_contract_address(), &candidate, &remainder);
+            }
+        }
+    }
+
     // ============================================================
     // FINALIZE
     // ============================================================
@@ -156,7 +475,16 @@ impl SoroSusu {
             circle.payout_queue = circle.members.clone();
         }
 
+        let now = env.ledger().timestamp();
+        circle.round_started_at = now;
+        circle.round_deadline = now + circle.rotation_period;
+
         write_circle(&env, circle_id, &circle);
+
+        env.events().publish(
+            (Symbol::new(&env, "CircleFinalized"), circle_id),
+            circle.payout_queue.clone(),
+        );
     }
 
     // ============================================================
@@ -174,6 +502,19 @@ impl SoroSusu {
             panic_with_error!(&env, Error::AlreadyDissolved);
         }
 
+        if env.ledger().timestamp() < circle.round_deadline {
+            panic_with_error!(&env, Error::RoundNotDue);
+        }
+
+        let queue_head = circle
+            .payout_queue
+            .get(circle.current_payout_index)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotMember));
+
+        if queue_head != recipient {
+            panic_with_error!(&env, Error::OutOfOrder);
+        }
+
         let mut index = None;
         for (i, member) in circle.members.iter().enumerate() {
             if member == recipient {
@@ -188,9 +529,146 @@ impl SoroSusu {
             panic_with_error!(&env, Error::Unauthorized);
         }
 
+        for member in circle.payout_queue.iter() {
+            let mut member_index = None;
+            for (mi, m) in circle.members.iter().enumerate() {
+                if m == member {
+                    member_index = Some(mi);
+                    break;
+                }
+            }
+            let mi = member_index.unwrap_or_else(|| panic_with_error!(&env, Error::NotMember));
+            if !circle.round_contributions.get(mi).unwrap() {
+                panic_with_error!(&env, Error::RoundNotFunded);
+            }
+        }
+
         circle.has_received_payout.set(i, true);
         circle.current_payout_index += 1;
-        circle.total_volume_distributed += circle.contribution;
+
+        let round_amount = circle.round_pool;
+        let now = env.ledger().timestamp();
+
+        let vesting = VestingSchedule {
+            beneficiary: recipient.clone(),
+            total: round_amount,
+            start_ts: now,
+            duration: circle.vesting_duration,
+            claimed: 0,
+        };
+        write_vesting(&env, circle_id, i as u32, &vesting);
+
+        circle.total_volume_distributed += round_amount;
+        circle.round_started_at = now;
+        circle.round_deadline = now + circle.rotation_period;
+
+        // a fresh round starts collecting contributions from scratch
+        for idx in 0..circle.round_contributions.len() {
+            circle.round_contributions.set(idx, false);
+        }
+        circle.round_pool = 0;
+
+        write_circle(&env, circle_id, &circle);
+
+        env.events().publish(
+            (Symbol::new(&env, "PayoutProcessed"), circle_id),
+            (recipient, circle.current_payout_index, round_amount),
+        );
+    }
+
+    // ============================================================
+    // VESTING
+    // ============================================================
+
+    pub fn claim_vested(env: Env, circle_id: u32) -> i128 {
+        let invoker = env.invoker();
+        let circle = read_circle(&env, circle_id);
+
+        let mut index = None;
+        for (i, member) in circle.members.iter().enumerate() {
+            if member == invoker {
+                index = Some(i);
+                break;
+            }
+        }
+
+        let i = index.unwrap_or_else(|| panic_with_error!(&env, Error::NotMember));
+        let mut vesting = read_vesting(&env, circle_id, i as u32);
+
+        let now = env.ledger().timestamp();
+        let elapsed = now.saturating_sub(vesting.start_ts).min(vesting.duration);
+        let unlocked = if vesting.duration == 0 {
+            vesting.total
+        } else {
+            vesting.total * elapsed as i128 / vesting.duration as i128
+        };
+
+        let claimable = unlocked - vesting.claimed;
+
+        if claimable > 0 {
+            vesting.claimed += claimable;
+            write_vesting(&env, circle_id, i as u32, &vesting);
+
+            let token_client = token::Client::new(&env, &circle.token);
+            token_client.transfer(&env.current_contract_address(), &invoker, &claimable);
+        }
+
+        claimable
+    }
+
+    // ============================================================
+    // STRIKES / SLASHING
+    // ============================================================
+
+    pub fn record_missed_contribution(env: Env, circle_id: u32, member: Address) {
+        let mut circle = read_circle(&env, circle_id);
+
+        if env.invoker() != circle.admin {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+
+        if circle.is_dissolved {
+            panic_with_error!(&env, Error::AlreadyDissolved);
+        }
+
+        let mut index = None;
+        for (i, m) in circle.members.iter().enumerate() {
+            if m == member {
+                index = Some(i);
+                break;
+            }
+        }
+
+        let i = index.unwrap_or_else(|| panic_with_error!(&env, Error::NotMember));
+
+        if circle.strikes.get(i).unwrap() >= circle.max_strikes {
+            panic_with_error!(&env, Error::MaxStrikesReached);
+        }
+
+        circle.strikes.set(i, circle.strikes.get(i).unwrap() + 1);
+
+        if circle.strikes.get(i).unwrap() >= circle.max_strikes {
+            let mut queue_index = None;
+            for (qi, m) in circle.payout_queue.iter().enumerate() {
+                if m == member {
+                    queue_index = Some(qi as u32);
+                    break;
+                }
+            }
+            if let Some(qi) = queue_index {
+                circle.payout_queue.remove(qi);
+
+                // removing a slot before the cursor shifts every later
+                // entry left by one; keep the cursor pointing at the same
+                // member it did before the removal.
+                if qi < circle.current_payout_index {
+                    circle.current_payout_index -= 1;
+                }
+            }
+
+            circle.slashed_pot += circle.contributions_paid.get(i).unwrap();
+            circle.contributions_paid.set(i, 0);
+        }
 
         write_circle(&env, circle_id, &circle);
     }
@@ -212,10 +690,13 @@ impl SoroSusu {
         }
 
         if !circle.dissolution_votes.contains(&invoker) {
-            circle.dissolution_votes.push_back(invoker);
+            circle.dissolution_votes.push_back(invoker.clone());
         }
 
         write_circle(&env, circle_id, &circle);
+
+        env.events()
+            .publish((Symbol::new(&env, "DissolutionProposed"), circle_id), invoker);
     }
 
     pub fn vote_dissolve(env: Env, circle_id: u32) {
@@ -234,16 +715,34 @@ impl SoroSusu {
             panic_with_error!(&env, Error::AlreadyVoted);
         }
 
-        circle.dissolution_votes.push_back(invoker);
+        circle.dissolution_votes.push_back(invoker.clone());
 
         let total_members = circle.members.len();
         let votes = circle.dissolution_votes.len();
+        let threshold = total_members / 2 + 1;
 
         if votes * 2 > total_members {
             circle.is_dissolved = true;
         }
 
         write_circle(&env, circle_id, &circle);
+
+        env.events().publish(
+            (Symbol::new(&env, "DissolutionVoted"), circle_id),
+            (invoker, votes, threshold),
+        );
+
+        if circle.is_dissolved {
+            // `claim_vested` already works after dissolution with no
+            // extra gate, so recipients still collect their vesting on
+            // the original schedule — dissolution doesn't need to force
+            // it open early, and `withdraw_pro_rata` already discounts a
+            // recipient's full vesting `total` (not just what they've
+            // claimed so far), so late withdrawals can't double-spend
+            // the balance a payout recipient is still owed.
+            env.events()
+                .publish((Symbol::new(&env, "CircleDissolved"), circle_id), ());
+        }
     }
 
     // ============================================================
@@ -270,16 +769,35 @@ impl SoroSusu {
 
         let contributed = circle.contributions_paid.get(i).unwrap();
         let received = if circle.has_received_payout.get(i).unwrap() {
-            circle.contribution
+            read_vesting(&env, circle_id, i as u32).total
         } else {
             0
         };
 
-        let refundable = contributed - received;
+        let mut refundable = contributed - received;
+
+        let in_good_standing = circle.strikes.get(i).unwrap() < circle.max_strikes;
+        if in_good_standing && !circle.slashed_pot_claimed.get(i).unwrap() {
+            let good_standing_members = circle
+                .strikes
+                .iter()
+                .filter(|strikes| *strikes < circle.max_strikes)
+                .count() as i128;
+
+            if good_standing_members > 0 {
+                refundable += circle.slashed_pot / good_standing_members;
+            }
+            circle.slashed_pot_claimed.set(i, true);
+        }
 
         if refundable > 0 {
             circle.contributions_paid.set(i, 0);
             write_circle(&env, circle_id, &circle);
+
+            let token_client = token::Client::new(&env, &circle.token);
+            token_client.transfer(&env.current_contract_address(), &invoker, &refundable);
+        } else {
+            write_circle(&env, circle_id, &circle);
         }
 
         refundable
@@ -292,4 +810,409 @@ impl SoroSusu {
     pub fn get_circle(env: Env, circle_id: u32) -> Circle {
         read_circle(&env, circle_id)
     }
+
+    pub fn current_round_status(env: Env, circle_id: u32) -> RoundStatus {
+        let circle = read_circle(&env, circle_id);
+
+        let recipient = circle
+            .payout_queue
+            .get(circle.current_payout_index)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotMember));
+
+        let now = env.ledger().timestamp();
+        let seconds_remaining = circle.round_deadline.saturating_sub(now);
+        let amount_collected = circle.round_pool;
+
+        RoundStatus {
+            recipient,
+            amount_collected,
+            seconds_remaining,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Events as _, Ledger, LedgerInfo};
+    use soroban_sdk::{vec, IntoVal};
+
+    fn create_token(env: &Env, admin: &Address) -> (Address, token::Client<'static>) {
+        let contract_address = env.register_stellar_asset_contract(admin.clone());
+        (
+            contract_address.clone(),
+            token::Client::new(env, &contract_address),
+        )
+    }
+
+    fn advance_time(env: &Env, by: u64) {
+        let info = env.ledger().get();
+        env.ledger().set(LedgerInfo {
+            timestamp: info.timestamp + by,
+            ..info
+        });
+    }
+
+    fn setup_funded_circle(
+        env: &Env,
+        admin: &Address,
+        members: &[Address],
+        contribution: i128,
+        rotation_period: u64,
+        max_strikes: u32,
+    ) -> (
+        Address,
+        SoroSusuClient<'static>,
+        token::Client<'static>,
+        Address,
+        u32,
+    ) {
+        let (token_id, token_client) = create_token(env, admin);
+        let sac_client = token::StellarAssetClient::new(env, &token_id);
+
+        let contract_id = env.register_contract(None, SoroSusu);
+        let client = SoroSusuClient::new(env, &contract_id);
+
+        for member in members {
+            sac_client.mint(member, &(contribution * 10));
+        }
+
+        env.set_invoker(admin);
+        let circle_id = client.create_circle(
+            &contribution,
+            &token_id,
+            &false,
+            &max_strikes,
+            &rotation_period,
+            &0,
+            &0,
+        );
+
+        for member in members {
+            env.set_invoker(member);
+            client.join_circle(&circle_id);
+        }
+
+        env.set_invoker(admin);
+        client.finalize_circle(&circle_id);
+
+        (contract_id, client, token_client, token_id, circle_id)
+    }
+
+    #[test]
+    fn round_payout_requires_fresh_funding_before_advancing() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let members = [Address::generate(&env), Address::generate(&env)];
+        let contribution = 100;
+
+        let (_contract_id, client, token_client, _token_id, circle_id) =
+            setup_funded_circle(&env, &admin, &members, contribution, 1000, 3);
+
+        advance_time(&env, 1000);
+
+        // round 1 is already funded by the join-time escrow; the payout
+        // itself only opens a vesting schedule, it doesn't transfer yet.
+        env.set_invoker(&admin);
+        client.process_payout(&circle_id, &members[0]);
+
+        assert_eq!(
+            token_client.balance(&members[0]),
+            contribution * 10 - contribution
+        );
+
+        advance_time(&env, 1000);
+
+        // round 2 has not been re-funded yet: must be rejected.
+        let result = client.try_process_payout(&circle_id, &members[1]);
+        assert!(result.is_err());
+
+        for member in &members {
+            env.set_invoker(member);
+            client.contribute(&circle_id);
+        }
+
+        env.set_invoker(&admin);
+        client.process_payout(&circle_id, &members[1]);
+    }
+
+    #[test]
+    fn slashing_before_the_payout_cursor_keeps_it_pointing_at_the_right_member() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let members = [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let contribution = 100;
+
+        let (_contract_id, client, _token_client, _token_id, circle_id) =
+            setup_funded_circle(&env, &admin, &members, contribution, 1000, 1);
+
+        advance_time(&env, 1000);
+
+        // members[0] is paid first; the cursor now points at members[1].
+        env.set_invoker(&admin);
+        client.process_payout(&circle_id, &members[0]);
+
+        // members[0] is slashed after the fact and dropped from
+        // payout_queue at index 0 — a slot before the cursor.
+        client.record_missed_contribution(&circle_id, &members[0]);
+
+        for member in &members[1..] {
+            env.set_invoker(member);
+            client.contribute(&circle_id);
+        }
+
+        advance_time(&env, 1000);
+
+        // the cursor must still land on members[1], not skip to members[2].
+        env.set_invoker(&admin);
+        client.process_payout(&circle_id, &members[1]);
+    }
+
+    #[test]
+    fn vesting_unlocks_linearly_over_time() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let members = [Address::generate(&env), Address::generate(&env)];
+        let contribution = 100;
+        let vesting_duration = 1000;
+
+        let (_contract_id, client, token_client, circle_id) = {
+            let (token_id, token_client) = create_token(&env, &admin);
+            let sac_client = token::StellarAssetClient::new(&env, &token_id);
+
+            let contract_id = env.register_contract(None, SoroSusu);
+            let client = SoroSusuClient::new(&env, &contract_id);
+
+            for member in &members {
+                sac_client.mint(member, &(contribution * 10));
+            }
+
+            env.set_invoker(&admin);
+            let circle_id =
+                client.create_circle(&contribution, &token_id, &false, &3, &100, &vesting_duration, &0);
+
+            for member in &members {
+                env.set_invoker(member);
+                client.join_circle(&circle_id);
+            }
+
+            env.set_invoker(&admin);
+            client.finalize_circle(&circle_id);
+
+            (contract_id, client, token_client, circle_id)
+        };
+
+        advance_time(&env, 100);
+        client.process_payout(&circle_id, &members[0]);
+
+        let total = contribution * members.len() as i128;
+        let balance_before = token_client.balance(&members[0]);
+
+        advance_time(&env, vesting_duration / 2);
+        env.set_invoker(&members[0]);
+        let claimed = client.claim_vested(&circle_id);
+
+        assert_eq!(claimed, total / 2);
+        assert_eq!(token_client.balance(&members[0]), balance_before + total / 2);
+    }
+
+    #[test]
+    fn slashed_pot_is_redistributed_pro_rata_on_dissolution() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let members = [Address::generate(&env), Address::generate(&env)];
+        let contribution = 100;
+
+        let (_contract_id, client, token_client, _token_id, circle_id) =
+            setup_funded_circle(&env, &admin, &members, contribution, 1000, 1);
+
+        // one strike is enough to hit max_strikes = 1 for this test.
+        env.set_invoker(&admin);
+        client.record_missed_contribution(&circle_id, &members[1]);
+
+        env.set_invoker(&members[0]);
+        // proposing already records members[0]'s dissolution vote.
+        client.propose_dissolution(&circle_id);
+
+        // dissolution needs a majority of all members, defaulter included.
+        env.set_invoker(&members[1]);
+        client.vote_dissolve(&circle_id);
+
+        env.set_invoker(&members[0]);
+        let balance_before = token_client.balance(&members[0]);
+        let refunded = client.withdraw_pro_rata(&circle_id);
+
+        // the honest member gets their own escrow back plus the slashed
+        // member's forfeited contribution, since they are the only
+        // member left in good standing.
+        assert_eq!(refunded, contribution + contribution);
+        assert_eq!(
+            token_client.balance(&members[0]),
+            balance_before + refunded
+        );
+    }
+
+    #[test]
+    fn payout_before_round_deadline_is_rejected_then_accepted_after() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let members = [Address::generate(&env), Address::generate(&env)];
+        let contribution = 100;
+        let rotation_period = 1000;
+
+        let (_contract_id, client, _token_client, _token_id, circle_id) =
+            setup_funded_circle(&env, &admin, &members, contribution, rotation_period, 3);
+
+        env.set_invoker(&admin);
+        let result = client.try_process_payout(&circle_id, &members[0]);
+        assert!(result.is_err());
+
+        advance_time(&env, rotation_period);
+        client.process_payout(&circle_id, &members[0]);
+    }
+
+    #[test]
+    fn candidate_with_quorum_is_admitted_and_added_to_payout_queue() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let members = [Address::generate(&env), Address::generate(&env)];
+        let contribution = 100;
+
+        let (_contract_id, client, _token_client, token_id, circle_id) =
+            setup_funded_circle(&env, &admin, &members, contribution, 1000, 3);
+
+        let candidate = Address::generate(&env);
+        let sac_client = token::StellarAssetClient::new(&env, &token_id);
+        sac_client.mint(&candidate, &(contribution * 2));
+
+        env.set_invoker(&candidate);
+        client.bid_to_join(&circle_id, &contribution);
+
+        // both members vouch: 2 * 2 > 2, quorum met.
+        for member in &members {
+            env.set_invoker(member);
+            client.vouch(&circle_id, &candidate);
+        }
+
+        env.set_invoker(&admin);
+        client.admit_candidate(&circle_id, &candidate);
+
+        let circle = client.get_circle(&circle_id);
+        assert!(circle.members.contains(&candidate));
+
+        // the circle was already finalized, so admission must append the
+        // candidate to the live payout_queue directly, not just members.
+        assert!(circle.payout_queue.contains(&candidate));
+    }
+
+    #[test]
+    fn candidate_without_quorum_is_rejected_and_deposit_is_slashed() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let members = [Address::generate(&env), Address::generate(&env)];
+        let contribution = 100;
+        let wrong_side_deduction = 20;
+
+        let (token_id, token_client) = create_token(&env, &admin);
+        let sac_client = token::StellarAssetClient::new(&env, &token_id);
+
+        let _contract_id = env.register_contract(None, SoroSusu);
+        let client = SoroSusuClient::new(&env, &_contract_id);
+
+        for member in &members {
+            sac_client.mint(member, &(contribution * 10));
+        }
+
+        env.set_invoker(&admin);
+        let circle_id = client.create_circle(
+            &contribution,
+            &token_id,
+            &false,
+            &3,
+            &1000,
+            &0,
+            &wrong_side_deduction,
+        );
+
+        for member in &members {
+            env.set_invoker(member);
+            client.join_circle(&circle_id);
+        }
+
+        env.set_invoker(&admin);
+        client.finalize_circle(&circle_id);
+
+        let candidate = Address::generate(&env);
+        let deposit = contribution * 2;
+        sac_client.mint(&candidate, &deposit);
+
+        env.set_invoker(&candidate);
+        client.bid_to_join(&circle_id, &deposit);
+
+        // only one of two members vouches: 1 * 2 > 2 is false, no quorum.
+        env.set_invoker(&members[0]);
+        client.vouch(&circle_id, &candidate);
+
+        let balance_before = token_client.balance(&candidate);
+
+        env.set_invoker(&admin);
+        client.admit_candidate(&circle_id, &candidate);
+
+        let circle = client.get_circle(&circle_id);
+        assert!(!circle.members.contains(&candidate));
+        assert_eq!(circle.slashed_pot, wrong_side_deduction);
+        assert_eq!(
+            token_client.balance(&candidate),
+            balance_before + (deposit - wrong_side_deduction)
+        );
+    }
+
+    #[test]
+    fn circle_created_event_uses_the_full_length_topic_name() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let (token_id, _token_client) = create_token(&env, &admin);
+
+        let contract_id = env.register_contract(None, SoroSusu);
+        let client = SoroSusuClient::new(&env, &contract_id);
+
+        env.set_invoker(&admin);
+        let contribution = 100;
+        let circle_id = client.create_circle(&contribution, &token_id, &false, &3, &1000, &0, &0);
+
+        // `symbol_short!` tops out at 9 characters, too short for names
+        // like "CircleCreated" — assert the full `Symbol` topic an
+        // off-chain indexer built against the spec would look for.
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id,
+                    (Symbol::new(&env, "CircleCreated"), circle_id).into_val(&env),
+                    (admin, contribution).into_val(&env),
+                ),
+            ]
+        );
+    }
 }